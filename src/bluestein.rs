@@ -0,0 +1,149 @@
+use std::f64::consts::PI;
+
+use {Operation, Plan, Transform, c64};
+
+/// The Bluestein (chirp-z) state that `Plan` carries for sizes that are not
+/// a power of two.
+///
+/// The radix-2 engine in `complex` only supports sizes that are powers of
+/// two. `Bluestein` lifts that restriction by rewriting a length-`n` DFT as
+/// a length-`M` circular convolution, for `M` the next power of two at
+/// least `2n - 1`, which the radix-2 engine can then carry out directly.
+/// `Plan::new` builds one of these automatically whenever `size` is not a
+/// power of two, so `Plan`/`Transform` support arbitrary sizes transparently.
+///
+/// ## References
+///
+/// 1. Leo I. Bluestein, “A linear filtering approach to the computation of
+///    the discrete Fourier transform,” IEEE Transactions on Audio and
+///    Electroacoustics, 1970.
+pub(crate) struct Bluestein {
+    size: usize,
+    forward: Box<Plan>,
+    inverse: Box<Plan>,
+    kernel: Vec<c64>,
+}
+
+impl Bluestein {
+    pub(crate) fn new(operation: Operation, size: usize) -> Bluestein {
+        let sign = sign_of(operation);
+
+        let m = (2 * size - 1).next_power_of_two();
+        let forward = Plan::new(Operation::Forward, m);
+        let inverse = Plan::new(Operation::Inverse, m);
+
+        // The chirp kernel `c[j] = w^(-j^2 / 2)` for `j` in `-(n - 1)..n`,
+        // wrapped into a length-`M` buffer and stored as its own transform so
+        // it only has to be computed once per plan.
+        let mut kernel = vec![c64!(0.0, 0.0); m];
+        kernel[0] = chirp(0, size, -sign);
+        for j in 1..size {
+            let value = chirp(j, size, -sign);
+            kernel[j] = value;
+            kernel[m - j] = value;
+        }
+        kernel.transform(&forward);
+
+        Bluestein { size, forward: Box::new(forward), inverse: Box::new(inverse), kernel }
+    }
+
+    pub(crate) fn transform(&self, data: &mut [c64], operation: Operation) {
+        let n = self.size;
+        assert!(data.len() == n, "the plan is not appropriate for the dataset");
+
+        let sign = sign_of(operation);
+        let m = self.kernel.len();
+
+        let mut buffer = vec![c64!(0.0, 0.0); m];
+        for j in 0..n {
+            buffer[j] = data[j] * chirp(j, n, sign);
+        }
+
+        buffer.transform(&self.forward);
+        for (value, factor) in buffer.iter_mut().zip(self.kernel.iter()) {
+            *value = *value * *factor;
+        }
+        buffer.transform(&self.inverse);
+
+        for k in 0..n {
+            data[k] = chirp(k, n, sign) * buffer[k];
+        }
+
+        if let Operation::Inverse = operation {
+            let scale = 1.0 / n as f64;
+            for value in data.iter_mut() {
+                *value = value.scale(scale);
+            }
+        }
+    }
+}
+
+fn sign_of(operation: Operation) -> f64 {
+    match operation {
+        Operation::Forward => -1.0,
+        Operation::Backward | Operation::Inverse => 1.0,
+    }
+}
+
+/// Compute `w^(j^2 / 2)` for `w = exp(sign * 2*pi*i / n)`, reducing the
+/// exponent index modulo `2n` first to keep the angle well-conditioned for
+/// large `j`.
+fn chirp(j: usize, n: usize, sign: f64) -> c64 {
+    let index = (j * j) % (2 * n);
+    let angle = sign * PI * index as f64 / n as f64;
+    c64!(angle.cos(), angle.sin())
+}
+
+#[cfg(test)]
+mod tests {
+    use Operation;
+    use {Plan, Transform};
+    use c64;
+
+    fn direct_dft(data: &[c64], sign: f64) -> Vec<c64> {
+        let n = data.len();
+        let mut result = vec![c64!(0.0, 0.0); n];
+        for (k, out) in result.iter_mut().enumerate() {
+            let mut sum = c64!(0.0, 0.0);
+            for (j, &value) in data.iter().enumerate() {
+                let angle = sign * 2.0 * ::std::f64::consts::PI * (k * j) as f64 / n as f64;
+                sum = sum + value * c64!(angle.cos(), angle.sin());
+            }
+            *out = sum;
+        }
+        result
+    }
+
+    #[test]
+    fn matches_direct_dft_for_prime_size() {
+        let data = vec![c64!(1.0, 0.0), c64!(2.0, -1.0), c64!(0.0, 3.0), c64!(-1.0, 1.0), c64!(2.0, 0.5)];
+        let expected = direct_dft(&data, -1.0);
+
+        let plan = Plan::new(Operation::Forward, data.len());
+        let mut actual = data.clone();
+        actual.transform(&plan);
+
+        for (value, expected) in actual.iter().zip(&expected) {
+            assert!((value.re - expected.re).abs() < 1e-8);
+            assert!((value.im - expected.im).abs() < 1e-8);
+        }
+    }
+
+    #[test]
+    fn round_trip_for_non_power_of_two_size() {
+        let data = vec![c64!(1.0, 0.0), c64!(2.0, -1.0), c64!(0.0, 3.0), c64!(-1.0, 1.0), c64!(2.0, 0.5)];
+        let original = data.clone();
+
+        let forward = Plan::new(Operation::Forward, data.len());
+        let mut result = data.clone();
+        result.transform(&forward);
+
+        let inverse = Plan::new(Operation::Inverse, data.len());
+        result.transform(&inverse);
+
+        for (value, expected) in result.iter().zip(&original) {
+            assert!((value.re - expected.re).abs() < 1e-8);
+            assert!((value.im - expected.im).abs() < 1e-8);
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use {Operation, Plan, Transform};
+
+impl Transform for [::c64] {
+    /// Perform the transform.
+    ///
+    /// The number of points should equal `plan.size`. Sizes that are a power
+    /// of two are carried out in place with the iterative radix-2
+    /// Cooley–Tukey algorithm using the twiddle factors precomputed in
+    /// `plan`; any other size is delegated to the Bluestein plan that
+    /// `Plan::new` built for it.
+    fn transform(&mut self, plan: &Plan) {
+        let n = self.len();
+        assert!(n == plan.size, "the plan is not appropriate for the dataset");
+
+        if let Some(ref bluestein) = plan.bluestein {
+            bluestein.transform(self, plan.operation);
+            return;
+        }
+
+        let mut j = 0;
+        for i in 1..n {
+            let mut bit = n >> 1;
+            while j & bit != 0 {
+                j ^= bit;
+                bit >>= 1;
+            }
+            j |= bit;
+            if i < j {
+                self.swap(i, j);
+            }
+        }
+
+        let mut length = 2;
+        while length <= n {
+            let half = length / 2;
+            let step = n / length;
+            for start in (0..n).step_by(length) {
+                for k in 0..half {
+                    let w = plan.factors[k * step];
+                    let u = self[start + k];
+                    let v = self[start + k + half] * w;
+                    self[start + k] = u + v;
+                    self[start + k + half] = u - v;
+                }
+            }
+            length <<= 1;
+        }
+
+        if let Operation::Inverse = plan.operation {
+            let scale = 1.0 / n as f64;
+            for value in self.iter_mut() {
+                *value = value.scale(scale);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use Operation;
+    use Plan;
+    use Transform;
+
+    #[test]
+    fn round_trip() {
+        let data = vec![c64!(1.0, 0.0), c64!(2.0, -1.0), c64!(0.0, 3.0), c64!(-1.0, 1.0)];
+        let original = data.clone();
+
+        let forward = Plan::new(Operation::Forward, data.len());
+        let mut result = data.clone();
+        result.transform(&forward);
+
+        let inverse = Plan::new(Operation::Inverse, data.len());
+        result.transform(&inverse);
+
+        for (value, expected) in result.iter().zip(&original) {
+            assert!((value.re - expected.re).abs() < 1e-10);
+            assert!((value.im - expected.im).abs() < 1e-10);
+        }
+    }
+}
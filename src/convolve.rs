@@ -0,0 +1,154 @@
+use {Operation, Plan, Transform};
+
+/// Compute the circular convolution of two equally long real signals.
+///
+/// Both signals are forward-transformed in the packed half-spectrum format
+/// produced by `Transform::transform`, multiplied point-wise in the
+/// frequency domain, and inverse-transformed back to a real result. The
+/// length of `a` and `b` should be equal and a power of two.
+pub fn convolve(a: &[f64], b: &[f64]) -> Vec<f64> {
+    assert!(a.len() == b.len(), "the signals should have the same length");
+    combine(a, b, false)
+}
+
+/// Compute the circular cross-correlation of two equally long real signals.
+///
+/// This is identical to `convolve` except that the spectrum of `b` is
+/// conjugated before the point-wise multiplication.
+pub fn correlate(a: &[f64], b: &[f64]) -> Vec<f64> {
+    assert!(a.len() == b.len(), "the signals should have the same length");
+    combine(a, b, true)
+}
+
+/// Compute the circular autocorrelation of a real signal.
+pub fn autocorrelate(a: &[f64]) -> Vec<f64> {
+    combine(a, a, true)
+}
+
+/// Compute the linear convolution of two real signals.
+///
+/// Both signals are zero-padded to a power of two at least 2 and at least
+/// `a.len() + b.len() - 1` so that the circular convolution of the padded
+/// signals equals the linear convolution of the originals.
+pub fn convolve_linear(a: &[f64], b: &[f64]) -> Vec<f64> {
+    let n = (a.len() + b.len() - 1).max(2).next_power_of_two();
+    let mut result = combine(&pad(a, n), &pad(b, n), false);
+    result.truncate(a.len() + b.len() - 1);
+    result
+}
+
+/// Compute the linear cross-correlation of two real signals.
+///
+/// The result holds `a.len() + b.len() - 1` lags in the same wrap-around
+/// order as the circular correlation: lags `0..a.len()` first, followed by
+/// the negative lags `-(b.len() - 1)..0`.
+pub fn correlate_linear(a: &[f64], b: &[f64]) -> Vec<f64> {
+    let n = (a.len() + b.len() - 1).max(2).next_power_of_two();
+    let circular = combine(&pad(a, n), &pad(b, n), true);
+    let mut result = circular[..a.len()].to_vec();
+    result.extend_from_slice(&circular[n - b.len().saturating_sub(1)..]);
+    result
+}
+
+fn pad(data: &[f64], n: usize) -> Vec<f64> {
+    let mut padded = vec![0.0; n];
+    padded[..data.len()].copy_from_slice(data);
+    padded
+}
+
+fn combine(a: &[f64], b: &[f64], conjugate: bool) -> Vec<f64> {
+    let n = a.len();
+    assert!(n >= 2 && n.is_power_of_two(), "the number of points should be a power of two of at least 2");
+
+    let forward = Plan::new(Operation::Forward, n);
+    let mut x = a.to_vec();
+    let mut y = b.to_vec();
+    x.transform(&forward);
+    y.transform(&forward);
+
+    // `data[0]` and `data[1]` hold the real-only DC and Nyquist components;
+    // every other pair of entries packs one complex frequency bin.
+    x[0] *= y[0];
+    x[1] *= y[1];
+    for i in 1..(n / 2) {
+        let (xr, xi) = (x[2 * i], x[2 * i + 1]);
+        let (yr, yi) = if conjugate { (y[2 * i], -y[2 * i + 1]) } else { (y[2 * i], y[2 * i + 1]) };
+        x[2 * i] = xr * yr - xi * yi;
+        x[2 * i + 1] = xr * yi + xi * yr;
+    }
+
+    let inverse = Plan::new(Operation::Inverse, n);
+    x.transform(&inverse);
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{autocorrelate, convolve, convolve_linear, correlate_linear};
+
+    #[test]
+    fn shifted_impulse() {
+        let a = vec![1.0, 0.0, 0.0, 0.0];
+        let b = vec![0.0, 1.0, 0.0, 0.0];
+        let result = convolve(&a, &b);
+        for (value, expected) in result.iter().zip(&[0.0, 1.0, 0.0, 0.0]) {
+            assert!((value - expected).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn convolve_linear_with_working_length_two() {
+        // (2 + 3x) * 5 = 10 + 15x, which pads to a working length of 2.
+        let result = convolve_linear(&[2.0, 3.0], &[5.0]);
+        for (value, expected) in result.iter().zip(&[10.0, 15.0]) {
+            assert!((value - expected).abs() < 1e-8);
+        }
+    }
+
+    #[test]
+    fn convolve_linear_matches_hand_computed() {
+        // (1 + 2x) * (1 + x) = 1 + 3x + 2x^2
+        let a = vec![1.0, 2.0];
+        let b = vec![1.0, 1.0];
+        let result = convolve_linear(&a, &b);
+        for (value, expected) in result.iter().zip(&[1.0, 3.0, 2.0]) {
+            assert!((value - expected).abs() < 1e-8);
+        }
+    }
+
+    #[test]
+    fn correlate_linear_matches_circular_correlation_of_padded_signals() {
+        let a = [1.0, 2.0, 3.0];
+        let b = [0.0, 1.0];
+        let n = (a.len() + b.len() - 1).next_power_of_two();
+
+        let mut pa = vec![0.0; n];
+        pa[..a.len()].copy_from_slice(&a);
+        let mut pb = vec![0.0; n];
+        pb[..b.len()].copy_from_slice(&b);
+
+        // Reference definition of circular correlation: reference[k] = sum_i
+        // pa[i] * pb[(i - k) mod n].
+        let mut reference = vec![0.0; n];
+        for k in 0..n {
+            let mut sum = 0.0;
+            for i in 0..n {
+                sum += pa[i] * pb[(i + n - k) % n];
+            }
+            reference[k] = sum;
+        }
+
+        let result = correlate_linear(&a, &b);
+        for (value, expected) in result.iter().zip(&reference[..a.len() + b.len() - 1]) {
+            assert!((value - expected).abs() < 1e-8);
+        }
+    }
+
+    #[test]
+    fn autocorrelate_zero_lag_is_signal_energy() {
+        let a = vec![1.0, -2.0, 3.0, 0.5];
+        let energy: f64 = a.iter().map(|v| v * v).sum();
+        let result = autocorrelate(&a);
+        assert!((result[0] - energy).abs() < 1e-8);
+    }
+}
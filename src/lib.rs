@@ -0,0 +1,134 @@
+//! Discrete Fourier transform.
+
+use std::f64::consts::PI;
+
+/// A complex number with `f64` components.
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct c64 {
+    pub re: f64,
+    pub im: f64,
+}
+
+impl c64 {
+    /// Create a new complex number.
+    pub fn new(re: f64, im: f64) -> c64 {
+        c64 { re, im }
+    }
+
+    /// Return the complex conjugate.
+    pub fn conj(&self) -> c64 {
+        c64::new(self.re, -self.im)
+    }
+
+    /// Multiply by a real scalar.
+    pub fn scale(&self, factor: f64) -> c64 {
+        c64::new(self.re * factor, self.im * factor)
+    }
+}
+
+impl ::std::ops::Add for c64 {
+    type Output = c64;
+
+    fn add(self, other: c64) -> c64 {
+        c64::new(self.re + other.re, self.im + other.im)
+    }
+}
+
+impl ::std::ops::Sub for c64 {
+    type Output = c64;
+
+    fn sub(self, other: c64) -> c64 {
+        c64::new(self.re - other.re, self.im - other.im)
+    }
+}
+
+impl ::std::ops::Mul for c64 {
+    type Output = c64;
+
+    fn mul(self, other: c64) -> c64 {
+        c64::new(self.re * other.re - self.im * other.im, self.re * other.im + self.im * other.re)
+    }
+}
+
+macro_rules! c64 {
+    ($re:expr, $im:expr) => { $crate::c64::new($re, $im) };
+}
+
+/// An operation to perform.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Operation {
+    /// Forward transform.
+    Forward,
+    /// Backward (unnormalized inverse) transform.
+    Backward,
+    /// Inverse (normalized) transform.
+    Inverse,
+}
+
+/// A transform.
+pub trait Transform {
+    /// Perform the transform.
+    fn transform(&mut self, plan: &Plan);
+}
+
+/// A plan for a transform.
+///
+/// `Plan::new` accepts any size. Sizes that are a power of two are carried
+/// out with the iterative radix-2 Cooley–Tukey algorithm; any other size is
+/// carried out with Bluestein's chirp-z algorithm (see the `bluestein`
+/// module), which rewrites the transform as a power-of-two convolution.
+/// Even sizes also precompute a half-size `Plan`, which `Transform for
+/// [f64]` reuses for the complex transform that its packed real/half-complex
+/// trick is built on, so that the real-valued path supports any even size
+/// as well; it does not accept odd sizes.
+pub struct Plan {
+    operation: Operation,
+    size: usize,
+    factors: Vec<c64>,
+    bluestein: Option<bluestein::Bluestein>,
+    half: Option<Box<Plan>>,
+}
+
+impl Plan {
+    /// Create a plan for a transform of a given size and operation.
+    pub fn new(operation: Operation, size: usize) -> Plan {
+        let (factors, bluestein) = if size.is_power_of_two() {
+            (radix2_factors(operation, size), None)
+        } else {
+            (Vec::new(), Some(bluestein::Bluestein::new(operation, size)))
+        };
+        let half = if size > 0 && size.is_multiple_of(2) {
+            Some(Box::new(Plan::new(operation, size / 2)))
+        } else {
+            None
+        };
+
+        Plan { operation, size, factors, bluestein, half }
+    }
+}
+
+fn radix2_factors(operation: Operation, size: usize) -> Vec<c64> {
+    let sign = match operation {
+        Operation::Forward => -1.0,
+        Operation::Backward | Operation::Inverse => 1.0,
+    };
+    let mut factors = Vec::with_capacity(size / 2);
+    for k in 0..(size / 2) {
+        let angle = sign * 2.0 * PI * k as f64 / size as f64;
+        factors.push(c64::new(angle.cos(), angle.sin()));
+    }
+    factors
+}
+
+mod bluestein;
+mod complex;
+mod convolve;
+mod multidim;
+mod ntt;
+mod real;
+
+pub use convolve::{autocorrelate, convolve, convolve_linear, correlate, correlate_linear};
+pub use multidim::{transform as transform_nd, transform_real, transform_real_inverse, MultiPlan};
+pub use ntt::{multiply, IntegerPlan, IntegerTransform, MODULUS, ROOT};
+pub use real::unpack;
@@ -0,0 +1,170 @@
+use {Operation, Plan, Transform, c64};
+use real;
+
+/// A plan for a separable, multi-dimensional transform.
+///
+/// `Plan` only describes a one-dimensional transform; `MultiPlan` composes
+/// one `Plan` per axis and applies each in turn along its axis, which is the
+/// standard way to build 2D/3D transforms out of a 1D engine. Axes are
+/// listed from the outermost to the innermost (contiguous) one, matching the
+/// row-major layout of the data.
+pub struct MultiPlan {
+    shape: Vec<usize>,
+    plans: Vec<Plan>,
+}
+
+impl MultiPlan {
+    /// Create a plan for a transform of data with the given shape.
+    pub fn new(operation: Operation, shape: &[usize]) -> MultiPlan {
+        let plans = shape.iter().map(|&size| Plan::new(operation, size)).collect();
+        MultiPlan { shape: shape.to_vec(), plans }
+    }
+}
+
+/// Perform a complex, separable multi-dimensional transform in place.
+pub fn transform(data: &mut [c64], plan: &MultiPlan) {
+    let total: usize = plan.shape.iter().product();
+    assert!(data.len() == total, "the plan is not appropriate for the dataset");
+    for (axis, sub_plan) in plan.plans.iter().enumerate() {
+        transform_axis(data, &plan.shape, axis, sub_plan);
+    }
+}
+
+/// Perform a real-valued, separable multi-dimensional forward transform.
+///
+/// Real data only needs the non-redundant half of its spectrum. Following
+/// the usual r2c convention, the packed half-spectrum format of
+/// `Transform for [f64]`/`real::unpack` is applied along the last, contiguous
+/// axis, which shrinks that axis to `size / 2 + 1` complex entries; the
+/// remaining axes are then transformed as ordinary complex transforms.
+pub fn transform_real(data: &[f64], plan: &MultiPlan) -> Vec<c64> {
+    let last = plan.shape.len() - 1;
+    let last_size = plan.shape[last];
+    let half = last_size / 2 + 1;
+    let outer: usize = plan.shape[..last].iter().product();
+
+    let mut out_shape = plan.shape.clone();
+    out_shape[last] = half;
+    let total: usize = out_shape.iter().product();
+    let mut result = Vec::with_capacity(total);
+
+    let last_plan = &plan.plans[last];
+    for o in 0..outer {
+        let mut row = data[o * last_size..(o + 1) * last_size].to_vec();
+        row.transform(last_plan);
+        result.extend_from_slice(&real::unpack(&row)[..half]);
+    }
+
+    for axis in 0..last {
+        transform_axis(&mut result, &out_shape, axis, &plan.plans[axis]);
+    }
+    result
+}
+
+/// Perform a real-valued, separable multi-dimensional inverse transform,
+/// undoing `transform_real`.
+pub fn transform_real_inverse(spectrum: &[c64], plan: &MultiPlan) -> Vec<f64> {
+    let last = plan.shape.len() - 1;
+    let last_size = plan.shape[last];
+    let half = last_size / 2 + 1;
+    let outer: usize = plan.shape[..last].iter().product();
+
+    let mut in_shape = plan.shape.clone();
+    in_shape[last] = half;
+    let mut work = spectrum.to_vec();
+    for axis in 0..last {
+        transform_axis(&mut work, &in_shape, axis, &plan.plans[axis]);
+    }
+
+    let last_plan = &plan.plans[last];
+    let total: usize = plan.shape.iter().product();
+    let mut result = Vec::with_capacity(total);
+    for o in 0..outer {
+        let mut row = pack(&work[o * half..(o + 1) * half], last_size);
+        row.transform(last_plan);
+        result.extend_from_slice(&row);
+    }
+    result
+}
+
+/// Undo `real::unpack` for a single row.
+fn pack(spectrum: &[c64], n: usize) -> Vec<f64> {
+    let mut data = vec![0.0; n];
+    data[0] = spectrum[0].re;
+    data[1] = spectrum[n / 2].re;
+    for i in 1..(n / 2) {
+        data[2 * i] = spectrum[i].re;
+        data[2 * i + 1] = spectrum[i].im;
+    }
+    data
+}
+
+/// Apply a 1D transform along one axis of row-major data of the given shape.
+fn transform_axis(data: &mut [c64], shape: &[usize], axis: usize, plan: &Plan) {
+    let axis_size = shape[axis];
+    let inner: usize = shape[axis + 1..].iter().product();
+    let outer: usize = shape[..axis].iter().product();
+
+    let mut buffer = vec![c64!(0.0, 0.0); axis_size];
+    for o in 0..outer {
+        for i in 0..inner {
+            let base = o * axis_size * inner + i;
+            for k in 0..axis_size {
+                buffer[k] = data[base + k * inner];
+            }
+            buffer.transform(plan);
+            for k in 0..axis_size {
+                data[base + k * inner] = buffer[k];
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use Operation;
+    use super::{transform, transform_real, transform_real_inverse, MultiPlan};
+
+    #[test]
+    fn impulse_2d() {
+        let plan = MultiPlan::new(Operation::Forward, &[2, 2]);
+        let mut data = vec![c64!(1.0, 0.0), c64!(0.0, 0.0), c64!(0.0, 0.0), c64!(0.0, 0.0)];
+        transform(&mut data, &plan);
+        for value in data {
+            assert!((value.re - 1.0).abs() < 1e-10);
+            assert!(value.im.abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn real_round_trip_2d() {
+        let shape = [2, 4];
+        let data = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0];
+
+        let forward = MultiPlan::new(Operation::Forward, &shape);
+        let spectrum = transform_real(&data, &forward);
+
+        let inverse = MultiPlan::new(Operation::Inverse, &shape);
+        let result = transform_real_inverse(&spectrum, &inverse);
+
+        for (value, expected) in result.iter().zip(&data) {
+            assert!((value - expected).abs() < 1e-8);
+        }
+    }
+
+    #[test]
+    fn real_round_trip_2d_with_last_axis_size_2() {
+        let shape = [2, 2];
+        let data = vec![1.0, 2.0, 3.0, 4.0];
+
+        let forward = MultiPlan::new(Operation::Forward, &shape);
+        let spectrum = transform_real(&data, &forward);
+
+        let inverse = MultiPlan::new(Operation::Inverse, &shape);
+        let result = transform_real_inverse(&spectrum, &inverse);
+
+        for (value, expected) in result.iter().zip(&data) {
+            assert!((value - expected).abs() < 1e-8);
+        }
+    }
+}
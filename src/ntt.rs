@@ -0,0 +1,200 @@
+use Operation;
+
+/// The prime modulus of the default number-theoretic transform,
+/// `p = 998244353 = 119 * 2^23 + 1`.
+pub const MODULUS: u64 = 998_244_353;
+
+/// A primitive root of `MODULUS`.
+pub const ROOT: u64 = 3;
+
+/// A plan for an integer, number-theoretic transform (NTT).
+///
+/// Unlike `Plan`, which carries floating-point twiddle factors drawn from
+/// the complex roots of unity, `IntegerPlan` works in `Z/pZ` for an
+/// NTT-friendly prime `p` and carries the powers of an `n`-th root of unity
+/// modulo `p`, derived from a primitive root of `p`, instead of
+/// `factors: &[c64]`. The same iterative radix-2 structure used
+/// for `c64` transforms applies; only the arithmetic changes.
+pub struct IntegerPlan {
+    operation: Operation,
+    size: usize,
+    modulus: u64,
+    factors: Vec<u64>,
+}
+
+impl IntegerPlan {
+    /// Create a plan for a transform of a given size modulo the default NTT
+    /// prime `MODULUS` using the primitive root `ROOT`.
+    pub fn new(operation: Operation, size: usize) -> IntegerPlan {
+        IntegerPlan::with_modulus(operation, size, MODULUS, ROOT)
+    }
+
+    /// Create a plan for a transform of a given size modulo an arbitrary
+    /// NTT-friendly prime with a corresponding primitive root.
+    pub fn with_modulus(operation: Operation, size: usize, modulus: u64, root: u64) -> IntegerPlan {
+        assert!(size.is_power_of_two(), "the number of points should be a power of two");
+        assert!((modulus - 1).is_multiple_of(size as u64),
+                "the modulus should support a transform of this size");
+
+        let root = pow_mod(root, (modulus - 1) / size as u64, modulus);
+        let root = match operation {
+            Operation::Forward => root,
+            Operation::Backward | Operation::Inverse => inverse_mod(root, modulus),
+        };
+
+        let mut factors = Vec::with_capacity(size / 2);
+        let mut power = 1;
+        for _ in 0..(size / 2) {
+            factors.push(power);
+            power = mul_mod(power, root, modulus);
+        }
+
+        IntegerPlan { operation, size, modulus, factors }
+    }
+}
+
+/// An integer transform performed exactly in `Z/pZ`, with no floating-point
+/// rounding.
+pub trait IntegerTransform {
+    /// Perform the transform.
+    fn transform(&mut self, plan: &IntegerPlan);
+}
+
+impl IntegerTransform for [u64] {
+    fn transform(&mut self, plan: &IntegerPlan) {
+        let n = self.len();
+        assert!(n == plan.size, "the plan is not appropriate for the dataset");
+        let modulus = plan.modulus;
+
+        let mut j = 0;
+        for i in 1..n {
+            let mut bit = n >> 1;
+            while j & bit != 0 {
+                j ^= bit;
+                bit >>= 1;
+            }
+            j |= bit;
+            if i < j {
+                self.swap(i, j);
+            }
+        }
+
+        let mut length = 2;
+        while length <= n {
+            let half = length / 2;
+            let step = n / length;
+            for start in (0..n).step_by(length) {
+                for k in 0..half {
+                    let w = plan.factors[k * step];
+                    let u = self[start + k];
+                    let v = mul_mod(self[start + k + half], w, modulus);
+                    self[start + k] = (u + v) % modulus;
+                    self[start + k + half] = (u + modulus - v) % modulus;
+                }
+            }
+            length <<= 1;
+        }
+
+        if let Operation::Inverse = plan.operation {
+            let inverse_n = inverse_mod(n as u64, modulus);
+            for value in self.iter_mut() {
+                *value = mul_mod(*value, inverse_n, modulus);
+            }
+        }
+    }
+}
+
+/// Multiply two integer vectors exactly by transforming both, taking their
+/// point-wise modular product, and inverse-transforming the result, giving
+/// bit-exact polynomial/big-integer multiplication with no floating-point
+/// rounding.
+///
+/// This is only exact as long as every true (unreduced) output coefficient
+/// stays below `MODULUS`; larger coefficients wrap silently modulo
+/// `MODULUS` like any other modular arithmetic. Since each output
+/// coefficient is a sum of at most `min(a.len(), b.len())` products of input
+/// elements, this panics up front if `min(a.len(), b.len()) * max(a) *
+/// max(b)` could reach `MODULUS`, rather than returning a silently wrapped
+/// result. Callers whose coefficients may exceed `MODULUS` should split the
+/// inputs into smaller digits/chunks or use `IntegerPlan::with_modulus` with
+/// a larger NTT-friendly prime (combined via the Chinese remainder theorem
+/// if one prime still is not enough).
+pub fn multiply(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let max_a = a.iter().cloned().max().unwrap_or(0) as u128;
+    let max_b = b.iter().cloned().max().unwrap_or(0) as u128;
+    let terms = a.len().min(b.len()) as u128;
+    let bound = terms * max_a * max_b;
+    assert!(bound < MODULUS as u128,
+            "the largest possible output coefficient ({}) would overflow MODULUS ({}); \
+             use smaller/shorter inputs or a larger modulus via `IntegerPlan::with_modulus`",
+            bound, MODULUS);
+
+    let size = (a.len() + b.len()).next_power_of_two();
+
+    let mut x = vec![0; size];
+    x[..a.len()].copy_from_slice(a);
+    let mut y = vec![0; size];
+    y[..b.len()].copy_from_slice(b);
+
+    let forward = IntegerPlan::new(Operation::Forward, size);
+    x.transform(&forward);
+    y.transform(&forward);
+
+    for i in 0..size {
+        x[i] = mul_mod(x[i], y[i], MODULUS);
+    }
+
+    let inverse = IntegerPlan::new(Operation::Inverse, size);
+    x.transform(&inverse);
+    x
+}
+
+fn mul_mod(a: u64, b: u64, modulus: u64) -> u64 {
+    ((a as u128 * b as u128) % modulus as u128) as u64
+}
+
+fn pow_mod(mut base: u64, mut exponent: u64, modulus: u64) -> u64 {
+    let mut result = 1;
+    base %= modulus;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mul_mod(result, base, modulus);
+        }
+        base = mul_mod(base, base, modulus);
+        exponent >>= 1;
+    }
+    result
+}
+
+fn inverse_mod(a: u64, modulus: u64) -> u64 {
+    pow_mod(a, modulus - 2, modulus)
+}
+
+#[cfg(test)]
+mod tests {
+    use Operation;
+    use super::multiply;
+
+    #[test]
+    fn multiply_polynomials() {
+        // (1 + 2x + 3x^2) * (1 + x) = 1 + 3x + 5x^2 + 3x^3
+        assert_eq!(multiply(&[1, 2, 3], &[1, 1])[..4], [1, 3, 5, 3]);
+    }
+
+    #[test]
+    fn round_trip() {
+        use super::IntegerPlan;
+        use super::IntegerTransform;
+
+        let mut data = vec![1, 2, 3, 4, 5, 6, 7, 8];
+        let original = data.clone();
+
+        let forward = IntegerPlan::new(Operation::Forward, data.len());
+        data.transform(&forward);
+
+        let inverse = IntegerPlan::new(Operation::Inverse, data.len());
+        data.transform(&inverse);
+
+        assert_eq!(data, original);
+    }
+}
@@ -1,3 +1,5 @@
+use std::f64::consts::PI;
+
 use {Operation, Plan, Transform, c64};
 
 impl Transform for [f64] {
@@ -20,15 +22,20 @@ impl Transform for [f64] {
 
         let n = self.len();
         assert!(n == plan.size, "the plan is not appropriate for the dataset");
+        assert!(n.is_multiple_of(2), "the number of points should be even");
+        // The packed real/half-complex trick treats the data as an `n / 2`
+        // point complex signal, so it reuses the half-size `Plan` that
+        // `Plan::new` precomputed alongside this one (power of two or not).
+        let inner = plan.half.as_ref().expect("the plan has no precomputed half-size transform");
         let data = unsafe { from_raw_parts_mut(self.as_mut_ptr() as *mut c64, n / 2) };
         match plan.operation {
             Operation::Forward => {
-                data.transform(plan);
-                compose(data, n / 2, &plan.factors, false);
+                data.transform(inner);
+                compose(data, n, false);
             },
             Operation::Backward | Operation::Inverse => {
-                compose(data, n / 2, &plan.factors, true);
-                data.transform(plan);
+                compose(data, n, true);
+                data.transform(inner);
             },
         }
     }
@@ -38,9 +45,8 @@ impl Transform for [f64] {
 /// `Operation::Forward` applied to real data.
 pub fn unpack(data: &[f64]) -> Vec<c64> {
     let n = data.len();
-    assert!(n.is_power_of_two(), "the number of points should be a power of two");
-    let mut cdata = Vec::with_capacity(n);
-    unsafe { cdata.set_len(n) };
+    assert!(n.is_multiple_of(2), "the number of points should be even");
+    let mut cdata = vec![c64!(0.0, 0.0); n];
     cdata[0] = c64!(data[0], 0.0);
     for i in 1..(n / 2) {
         cdata[i] = c64!(data[2 * i], data[2 * i + 1]);
@@ -52,27 +58,63 @@ pub fn unpack(data: &[f64]) -> Vec<c64> {
     cdata
 }
 
+/// Recombine the `n / 2`-point complex spectrum of the interleaved data into
+/// the packed half-spectrum of the original `n`-point real signal.
+///
+/// `total` is the length of the real signal; unlike the previous version of
+/// this function, the recombination twiddles are computed directly from it
+/// rather than looked up in a precomputed `Plan::factors` table, so this
+/// works whether or not `total` is a power of two.
 #[inline(always)]
-fn compose(data: &mut [c64], n: usize, factors: &[c64], inverse: bool) {
+fn compose(data: &mut [c64], total: usize, inverse: bool) {
     data[0] = c64!(data[0].re + data[0].im, data[0].re - data[0].im);
     if inverse {
         data[0] = data[0].scale(0.5);
     }
-    let m = factors.len();
+    let n = data.len();
     let sign = if inverse { 1.0 } else { -1.0 };
-    for i in 1..(n / 2) {
+    for i in 1..((n + 1) / 2) {
         let j = n - i;
         let part1 = data[i] + data[j].conj();
         let part2 = data[i] - data[j].conj();
-        let product = c64!(0.0, sign) * factors[m - j] * part2;
+        let angle = sign * 2.0 * PI * i as f64 / total as f64;
+        let twiddle = c64!(angle.cos(), angle.sin());
+        let product = c64!(0.0, sign) * twiddle * part2;
         data[i] = (part1 + product).scale(0.5);
         data[j] = (part1 - product).scale(0.5).conj();
     }
-    data[n / 2] = data[n / 2].conj();
+    // Only an even `n` has a self-paired middle entry `n / 2` left to
+    // conjugate. For odd `n` the loop above has covered every pair, and for
+    // `n == 1` (a real signal of length 2) `n / 2` is index `0`, which already
+    // holds the full DC/Nyquist pack and must not be conjugated again.
+    if n.is_multiple_of(2) {
+        data[n / 2] = data[n / 2].conj();
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use Operation;
+    use Plan;
+    use Transform;
+
+    #[test]
+    fn round_trip_length_2() {
+        let data = vec![2.0, 3.0];
+        let original = data.clone();
+
+        let forward = Plan::new(Operation::Forward, data.len());
+        let mut result = data.clone();
+        result.transform(&forward);
+
+        let inverse = Plan::new(Operation::Inverse, data.len());
+        result.transform(&inverse);
+
+        for (value, expected) in result.iter().zip(&original) {
+            assert!((value - expected).abs() < 1e-10);
+        }
+    }
+
     #[test]
     fn unpack() {
         let data = (0..4).map(|i| (i + 1) as f64).collect::<Vec<_>>();